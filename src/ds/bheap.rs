@@ -1,8 +1,7 @@
 use std::cmp::Ordering;
-use std::ops::Index;
+use std::collections::TryReserveError;
+use std::ops::{Deref, DerefMut, Index};
 use std::fmt::{Formatter, Debug};
-use std::alloc::{alloc_zeroed, Layout};
-use std::mem::{size_of, align_of};
 
 pub type Result<O> = std::result::Result<O, Error>;
 
@@ -12,6 +11,7 @@ pub enum ErrorKind {
     NoParent,
     NoChildren,
     OutOfBounds(usize),
+    Alloc(TryReserveError),
     Custom(String),
 }
 
@@ -38,9 +38,16 @@ pub struct BinaryHeap<T, Cmp> {
 }
 
 // construct and helper
+//
+// The public API is 1-indexed (index 0 is reserved and always an error), but
+// `inner` is a plain, densely-packed `Vec<T>` with no padding slot at index 0
+// — there is no value of type `T` that needs to exist just to make the
+// indexing math line up. `check` only validates an external index and returns
+// it unchanged; code that touches `inner` subtracts one from the external
+// index itself.
 impl<T, Cmp> BinaryHeap<T, Cmp> {
     pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
-        self.inner.swap(a, b);
+        self.inner.swap(a - 1, b - 1);
     }
 
     pub fn swap(&mut self, a: usize, b: usize) -> Result<()> {
@@ -52,10 +59,12 @@ impl<T, Cmp> BinaryHeap<T, Cmp> {
         }
     }
 
+    // validates and returns the external (1-indexed) index unchanged; callers
+    // that need to touch `inner` subtract one themselves
     fn check(&self, index: usize) -> Result<usize> {
         match index {
             0 => Err(Error { kind: ErrorKind::ZeroIndex }),
-            idx => if index < self.inner.len() {
+            idx => if idx <= self.inner.len() {
                 Ok(idx)
             } else {
                 Err(Error { kind: ErrorKind::OutOfBounds(idx) })
@@ -64,9 +73,9 @@ impl<T, Cmp> BinaryHeap<T, Cmp> {
     }
 
     pub fn get(&self, index: usize) -> Option<&T> {
-        self.check(index).ok()?;
+        let index = self.check(index).ok()?;
 
-        self.inner.get(index)
+        self.inner.get(index - 1)
     }
 
     pub const unsafe fn from_source_unchecked(source: Vec<T>, compare: Cmp) -> Self {
@@ -76,23 +85,9 @@ impl<T, Cmp> BinaryHeap<T, Cmp> {
         }
     }
 
-    pub fn from_source(source: Vec<T>, compare: Cmp) -> Option<Self> {
-        if source.is_empty() {
-            None
-        } else {
-            unsafe {
-                Some(Self::from_source_unchecked(source, compare))
-            }
-        }
-    }
-
     pub fn new(compare: Cmp) -> Self {
-        let layout = Layout::from_size_align(size_of::<T>(), align_of::<T>()).unwrap();
-        let zeroed = unsafe { alloc_zeroed(layout) as *mut T };
-        let unsafe_vec = unsafe { Vec::from_raw_parts(zeroed, 1, 1) };
-
         BinaryHeap {
-            inner: unsafe_vec,
+            inner: Vec::new(),
             compare,
         }
     }
@@ -123,7 +118,7 @@ impl<T, Cmp> BinaryHeap<T, Cmp> {
     }
 
     pub fn end(&self) -> usize {
-        self.inner.len() - 1
+        self.inner.len()
     }
 
     pub fn inner(&self) -> &Vec<T> {
@@ -147,9 +142,42 @@ impl<T, Cmp> Index<usize> for BinaryHeap<T, Cmp> {
 impl<T, Cmp> BinaryHeap<T, Cmp>
     where Cmp: Fn(&T, &T) -> Ordering {
     fn sink(&mut self, target: usize) -> Result<()> {
-        self.check(target)?;
+        self.sink_within(target, self.end())
+    }
+
+    // same downward sift as `sink`, but treats `bound` as the logical end of
+    // the heap instead of `self.end()`, so `into_sorted_vec` can sift within
+    // the shrinking unsorted prefix without touching the already-sorted tail
+    fn sink_within(&mut self, target: usize, bound: usize) -> Result<()> {
+        let mut target = self.check(target)?;
+
+        loop {
+            let (lc, rc) = self.children(target)?;
+            let lc = lc.filter(|&lc| lc <= bound);
+            let rc = rc.filter(|&rc| rc <= bound);
+
+            let best = match (lc, rc) {
+                (Some(lc), Some(rc)) => match (self.compare)(&self[lc], &self[rc]) {
+                    Ordering::Greater => lc,
+                    _ => rc,
+                }
 
-        todo!()
+                (Some(lc), None) => lc,
+                (None, None) => break,
+                (None, Some(_)) => unreachable!("left child is always filled before right"),
+            };
+
+            match (self.compare)(&self[best], &self[target]) {
+                Ordering::Greater => unsafe {
+                    self.swap_unchecked(target, best);
+                    target = best;
+                }
+
+                _ => break,
+            }
+        }
+
+        Ok(())
     }
 
     fn float(&mut self, target: usize) -> Result<()> {
@@ -174,6 +202,141 @@ impl<T, Cmp> BinaryHeap<T, Cmp>
 
         self.float(self.end());
     }
+
+    pub fn try_reserve(&mut self, additional: usize) -> Result<()> {
+        self.inner.try_reserve(additional)
+            .map_err(|err| Error { kind: ErrorKind::Alloc(err) })
+    }
+
+    pub fn try_push(&mut self, value: T) -> Result<()> {
+        self.try_reserve(1)?;
+
+        self.push(value);
+
+        Ok(())
+    }
+
+    pub fn from_source(source: Vec<T>, compare: Cmp) -> Option<Self> {
+        if source.is_empty() {
+            None
+        } else {
+            let mut heap = unsafe {
+                Self::from_source_unchecked(source, compare)
+            };
+
+            for i in (1..=heap.end() / 2).rev() {
+                heap.sink(i).unwrap();
+            }
+
+            Some(heap)
+        }
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.get(1)
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.inner.is_empty() {
+            return None;
+        }
+
+        let end = self.end();
+        unsafe {
+            self.swap_unchecked(1, end);
+        }
+
+        let popped = self.inner.pop();
+
+        if !self.inner.is_empty() {
+            self.sink(1).unwrap();
+        }
+
+        popped
+    }
+
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut end = self.end();
+
+        while end > 1 {
+            unsafe {
+                self.swap_unchecked(1, end);
+            }
+
+            end -= 1;
+            self.sink_within(1, end).unwrap();
+        }
+
+        self.inner
+    }
+
+    pub fn drain_sorted(&mut self) -> DrainSorted<'_, T, Cmp> {
+        DrainSorted { heap: self }
+    }
+
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, Cmp>> {
+        if self.inner.is_empty() {
+            None
+        } else {
+            Some(PeekMut { heap: self, sift: false })
+        }
+    }
+}
+
+pub struct PeekMut<'h, T, Cmp>
+    where Cmp: Fn(&T, &T) -> Ordering {
+    heap: &'h mut BinaryHeap<T, Cmp>,
+    sift: bool,
+}
+
+impl<'h, T, Cmp> Deref for PeekMut<'h, T, Cmp>
+    where Cmp: Fn(&T, &T) -> Ordering {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.heap[1]
+    }
+}
+
+impl<'h, T, Cmp> DerefMut for PeekMut<'h, T, Cmp>
+    where Cmp: Fn(&T, &T) -> Ordering {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.sift = true;
+
+        unsafe {
+            self.heap.inner_mut().get_unchecked_mut(0)
+        }
+    }
+}
+
+impl<'h, T, Cmp> Drop for PeekMut<'h, T, Cmp>
+    where Cmp: Fn(&T, &T) -> Ordering {
+    fn drop(&mut self) {
+        if self.sift {
+            self.heap.sink(1).unwrap();
+        }
+    }
+}
+
+pub struct DrainSorted<'h, T, Cmp>
+    where Cmp: Fn(&T, &T) -> Ordering {
+    heap: &'h mut BinaryHeap<T, Cmp>,
+}
+
+impl<'h, T, Cmp> Iterator for DrainSorted<'h, T, Cmp>
+    where Cmp: Fn(&T, &T) -> Ordering {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.heap.pop()
+    }
+}
+
+impl<'h, T, Cmp> Drop for DrainSorted<'h, T, Cmp>
+    where Cmp: Fn(&T, &T) -> Ordering {
+    fn drop(&mut self) {
+        while self.heap.pop().is_some() {}
+    }
 }
 
 impl <T: Debug, Cmp> Debug for BinaryHeap<T, Cmp> {
@@ -187,8 +350,8 @@ mod tests {
     use crate::ds::bheap::BinaryHeap;
     use std::cmp::Ordering;
 
-    fn construct() -> BinaryHeap<u32, ()> {
-        BinaryHeap::from_source(vec![0, 1, 2, 3, 4, 5, 6, 7, 8], ()).unwrap()
+    fn construct() -> BinaryHeap<u32, fn(&u32, &u32) -> Ordering> {
+        BinaryHeap::from_source(vec![0, 1, 2, 3, 4, 5, 6, 7, 8], u32::cmp as fn(&u32, &u32) -> Ordering).unwrap()
     }
 
     #[test]
@@ -204,7 +367,7 @@ mod tests {
         assert_eq!(Some(3), heap.parent(6).ok());
         assert_eq!(Some(3), heap.parent(7).ok());
         assert_eq!(Some(4), heap.parent(8).ok());
-        assert_eq!(None, heap.parent(9).ok());
+        assert_eq!(Some(4), heap.parent(9).ok());
     }
 
     #[test]
@@ -215,7 +378,7 @@ mod tests {
         assert_eq!(Some((Some(2), Some(3))), heap.children(1).ok());
         assert_eq!(Some((Some(4), Some(5))), heap.children(2).ok());
         assert_eq!(Some((Some(6), Some(7))), heap.children(3).ok());
-        assert_eq!(Some((Some(8), None)), heap.children(4).ok());
+        assert_eq!(Some((Some(8), Some(9))), heap.children(4).ok());
         assert_eq!(Some((None, None)), heap.children(5).ok());
     }
 
@@ -224,12 +387,94 @@ mod tests {
         let mut heap: BinaryHeap<u32, fn(&u32, &u32) -> Ordering> = BinaryHeap::new(|a, b| u32::cmp(a, b));
 
         heap.push(5);
-        assert_eq!(&vec![0, 5], heap.inner());
+        assert_eq!(&vec![5], heap.inner());
         heap.push(2);
-        assert_eq!(&vec![0, 5, 2], heap.inner());
+        assert_eq!(&vec![5, 2], heap.inner());
         heap.push(3);
-        assert_eq!(&vec![0, 5, 2, 3], heap.inner());
+        assert_eq!(&vec![5, 2, 3], heap.inner());
         heap.push(4);
-        assert_eq!(&vec![0, 5, 4, 3, 2], heap.inner());
+        assert_eq!(&vec![5, 4, 3, 2], heap.inner());
+    }
+
+    #[test]
+    fn heapify() {
+        let heap = construct();
+
+        for i in 1..=heap.end() {
+            let (lc, rc) = heap.children(i).unwrap();
+
+            if let Some(lc) = lc {
+                assert!(heap[i] >= heap[lc]);
+            }
+
+            if let Some(rc) = rc {
+                assert!(heap[i] >= heap[rc]);
+            }
+        }
+    }
+
+    #[test]
+    fn peek_and_pop() {
+        let mut heap: BinaryHeap<u32, fn(&u32, &u32) -> Ordering> = BinaryHeap::new(|a, b| u32::cmp(a, b));
+
+        heap.push(5);
+        heap.push(2);
+        heap.push(8);
+        heap.push(1);
+
+        assert_eq!(Some(&8), heap.peek());
+        assert_eq!(Some(8), heap.pop());
+        assert_eq!(Some(5), heap.pop());
+        assert_eq!(Some(2), heap.pop());
+        assert_eq!(Some(1), heap.pop());
+        assert_eq!(None, heap.pop());
+    }
+
+    #[test]
+    fn into_sorted_vec() {
+        let heap = construct();
+
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7, 8], heap.into_sorted_vec());
+    }
+
+    #[test]
+    fn drain_sorted() {
+        let mut heap = construct();
+
+        assert_eq!(vec![8, 7, 6, 5, 4, 3, 2, 1, 0], heap.drain_sorted().collect::<Vec<_>>());
+        assert_eq!(None, heap.pop());
+    }
+
+    #[test]
+    fn peek_mut_sifts_on_mutation() {
+        let mut heap: BinaryHeap<u32, fn(&u32, &u32) -> Ordering> = BinaryHeap::new(|a, b| u32::cmp(a, b));
+
+        heap.push(5);
+        heap.push(2);
+        heap.push(8);
+
+        *heap.peek_mut().unwrap() = 0;
+
+        assert_eq!(Some(&5), heap.peek());
+    }
+
+    #[test]
+    fn peek_mut_read_only_skips_sift() {
+        let mut heap: BinaryHeap<u32, fn(&u32, &u32) -> Ordering> = BinaryHeap::new(|a, b| u32::cmp(a, b));
+
+        heap.push(5);
+        heap.push(2);
+        heap.push(8);
+
+        assert_eq!(8, *heap.peek_mut().unwrap());
+        assert_eq!(Some(&8), heap.peek());
+    }
+
+    #[test]
+    fn try_push() {
+        let mut heap: BinaryHeap<u32, fn(&u32, &u32) -> Ordering> = BinaryHeap::new(|a, b| u32::cmp(a, b));
+
+        assert_eq!(Ok(()), heap.try_push(5));
+        assert_eq!(Some(&5), heap.peek());
     }
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::ops::Deref;
 
 #[derive(Debug)]
@@ -39,7 +40,15 @@ impl<T> BinaryTreeNode<T> {
     }
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Order {
+    Pre,
+    In,
+    Post,
+}
+
 pub struct DfsIter<'r, T> {
+    order: Order,
     // a stack which contains current node and a state
     stack: Vec<(&'r BinaryTreeNode<T>, u8)>
 }
@@ -48,32 +57,69 @@ impl<'r, T> Iterator for DfsIter<'r, T> {
     type Item = &'r T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.stack.pop() {
-            None => None,
-            Some((BinaryTreeNode::Leaf, _)) => self.next(),
-            Some((node, state)) => {
-                match state {
-                    0 => {
-                        self.stack.push((node, 1));
-                        Some(node.value().unwrap())
+        loop {
+            match self.stack.pop() {
+                None => return None,
+                Some((BinaryTreeNode::Leaf, _)) => continue,
+                Some((node, state)) => {
+                    match state {
+                        0 => {
+                            self.stack.push((node, 1));
+
+                            if self.order == Order::Pre {
+                                return Some(node.value().unwrap());
+                            }
+                        }
+
+                        1 => {
+                            self.stack.push((node, 2));
+                            self.stack.push((node.left(), 0));
+                        }
+
+                        2 => {
+                            self.stack.push((node, 3));
+                            self.stack.push((node.right(), 0));
+
+                            if self.order == Order::In {
+                                return Some(node.value().unwrap());
+                            }
+                        }
+
+                        3 => {
+                            if self.order == Order::Post {
+                                return Some(node.value().unwrap());
+                            }
+                        }
+
+                        _ => {}
                     }
+                }
+            }
+        }
+    }
+}
 
-                    1 => {
-                        self.stack.push((node, 2));
-                        self.stack.push((node.left(), 0));
-
-                        self.next()
-                    }
+pub struct BfsIter<'r, T> {
+    queue: VecDeque<&'r BinaryTreeNode<T>>,
+}
 
-                    2 => {
-                        self.stack.push((node, 3));
-                        self.stack.push((node.right(), 0));
+impl<'r, T> Iterator for BfsIter<'r, T> {
+    type Item = &'r T;
 
-                        self.next()
-                    }
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.queue.pop_front() {
+            None => None,
+            Some(BinaryTreeNode::Leaf) => self.next(),
+            Some(node) => {
+                if let BinaryTreeNode::Node { .. } = node.left() {
+                    self.queue.push_back(node.left());
+                }
 
-                    _ => self.next()
+                if let BinaryTreeNode::Node { .. } = node.right() {
+                    self.queue.push_back(node.right());
                 }
+
+                Some(node.value().unwrap())
             }
         }
     }
@@ -91,20 +137,26 @@ impl<T> BinaryTree<T> {
         }
     }
 
-    pub fn dfs(&self) -> DfsIter<T> {
+    pub fn iter(&self, order: Order) -> DfsIter<T> {
         DfsIter {
+            order,
             stack: vec![(&self.root, 0)]
         }
     }
+
+    pub fn bfs(&self) -> BfsIter<T> {
+        BfsIter {
+            queue: VecDeque::from([&self.root])
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ds::binary_tree::{BinaryTree, BinaryTreeNode};
+    use crate::ds::binary_tree::{BinaryTree, BinaryTreeNode, Order};
 
-    #[test]
-    fn dfs() {
-        let mut root = BinaryTree {
+    fn construct() -> BinaryTree<i32> {
+        BinaryTree {
             root: BinaryTreeNode::Node {
                 value: 0,
                 children: (
@@ -118,12 +170,34 @@ mod tests {
                     BinaryTreeNode::new(2).into()
                 ),
             }
-        };
+        }
+    }
 
-        println!("{:?}", root);
+    #[test]
+    fn preorder() {
+        let root = construct();
 
-        for node in root.dfs() {
-            println!("{:?}", node);
-        }
+        assert_eq!(vec![&0, &1, &3, &4, &2], root.iter(Order::Pre).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn inorder() {
+        let root = construct();
+
+        assert_eq!(vec![&3, &1, &4, &0, &2], root.iter(Order::In).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn postorder() {
+        let root = construct();
+
+        assert_eq!(vec![&3, &4, &1, &2, &0], root.iter(Order::Post).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn bfs() {
+        let root = construct();
+
+        assert_eq!(vec![&0, &1, &2, &3, &4], root.bfs().collect::<Vec<_>>());
     }
 }
\ No newline at end of file
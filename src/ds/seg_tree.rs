@@ -0,0 +1,220 @@
+use std::fmt::Formatter;
+use std::ops::Range;
+
+pub type Result<O> = std::result::Result<O, Error>;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    OutOfBounds(usize),
+    InvalidRange { start: usize, end: usize },
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Error occurred because of: {:?}", self.kind)
+    }
+}
+
+pub trait Monoid {
+    type T: Clone;
+
+    fn identity() -> Self::T;
+    fn combine(&self, a: &Self::T, b: &Self::T) -> Self::T;
+}
+
+pub struct SegTree<M: Monoid> {
+    n: usize,
+    inner: Vec<M::T>,
+    monoid: M,
+}
+
+impl<M: Monoid> SegTree<M> {
+    pub fn build(slice: &[M::T], monoid: M) -> Self {
+        let n = slice.len();
+        let mut inner = vec![M::identity(); 2 * n];
+
+        inner[n..2 * n].clone_from_slice(slice);
+
+        for i in (1..n).rev() {
+            inner[i] = monoid.combine(&inner[2 * i], &inner[2 * i + 1]);
+        }
+
+        SegTree { n, inner, monoid }
+    }
+
+    // validates a slice-relative index against `n`; callers that need to
+    // touch `inner` still have to offset by `n` themselves
+    fn check(&self, index: usize) -> Result<usize> {
+        if index < self.n {
+            Ok(index)
+        } else {
+            Err(Error { kind: ErrorKind::OutOfBounds(index) })
+        }
+    }
+
+    pub fn set(&mut self, i: usize, value: M::T) -> Result<()> {
+        let mut i = self.n + self.check(i)?;
+        self.inner[i] = value;
+
+        i /= 2;
+        while i >= 1 {
+            self.inner[i] = self.monoid.combine(&self.inner[2 * i], &self.inner[2 * i + 1]);
+            i /= 2;
+        }
+
+        Ok(())
+    }
+
+    pub fn query(&self, range: Range<usize>) -> Result<M::T> {
+        if range.start > range.end || range.end > self.n {
+            return Err(Error { kind: ErrorKind::InvalidRange { start: range.start, end: range.end } });
+        }
+
+        let mut l = range.start + self.n;
+        let mut r = range.end + self.n;
+        let mut result_l = M::identity();
+        let mut result_r = M::identity();
+
+        while l < r {
+            if l % 2 == 1 {
+                result_l = self.monoid.combine(&result_l, &self.inner[l]);
+                l += 1;
+            }
+
+            if r % 2 == 1 {
+                r -= 1;
+                result_r = self.monoid.combine(&self.inner[r], &result_r);
+            }
+
+            l /= 2;
+            r /= 2;
+        }
+
+        Ok(self.monoid.combine(&result_l, &result_r))
+    }
+}
+
+pub struct SumMonoid;
+
+impl SumMonoid {
+    pub fn new() -> Self {
+        SumMonoid
+    }
+}
+
+impl Monoid for SumMonoid {
+    type T = u64;
+
+    fn identity() -> Self::T {
+        0
+    }
+
+    fn combine(&self, a: &Self::T, b: &Self::T) -> Self::T {
+        a + b
+    }
+}
+
+pub struct MinMonoid;
+
+impl MinMonoid {
+    pub fn new() -> Self {
+        MinMonoid
+    }
+}
+
+impl Monoid for MinMonoid {
+    type T = u64;
+
+    fn identity() -> Self::T {
+        u64::MAX
+    }
+
+    fn combine(&self, a: &Self::T, b: &Self::T) -> Self::T {
+        *a.min(b)
+    }
+}
+
+pub struct MaxMonoid;
+
+impl MaxMonoid {
+    pub fn new() -> Self {
+        MaxMonoid
+    }
+}
+
+impl Monoid for MaxMonoid {
+    type T = u64;
+
+    fn identity() -> Self::T {
+        u64::MIN
+    }
+
+    fn combine(&self, a: &Self::T, b: &Self::T) -> Self::T {
+        *a.max(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ds::seg_tree::{ErrorKind, MaxMonoid, MinMonoid, SegTree, SumMonoid};
+
+    #[test]
+    fn range_sum() {
+        let mut tree = SegTree::build(&[1u64, 2, 3, 4, 5], SumMonoid::new());
+
+        assert_eq!(15, tree.query(0..5).unwrap());
+        assert_eq!(5, tree.query(1..3).unwrap());
+
+        tree.set(1, 10).unwrap();
+        assert_eq!(23, tree.query(0..5).unwrap());
+    }
+
+    #[test]
+    fn range_min() {
+        let tree = SegTree::build(&[5u64, 2, 8, 1, 9], MinMonoid::new());
+
+        assert_eq!(1, tree.query(0..5).unwrap());
+        assert_eq!(2, tree.query(0..2).unwrap());
+        assert_eq!(8, tree.query(2..3).unwrap());
+    }
+
+    #[test]
+    fn range_max() {
+        let tree = SegTree::build(&[5u64, 2, 8, 1, 9], MaxMonoid::new());
+
+        assert_eq!(9, tree.query(0..5).unwrap());
+        assert_eq!(5, tree.query(0..2).unwrap());
+    }
+
+    #[test]
+    fn set_out_of_bounds() {
+        let mut tree = SegTree::build(&[1u64, 2, 3, 4, 5], SumMonoid::new());
+
+        assert_eq!(ErrorKind::OutOfBounds(5), tree.set(5, 10).unwrap_err().kind);
+    }
+
+    #[test]
+    fn query_start_after_end() {
+        let tree = SegTree::build(&[1u64, 2, 3, 4, 5], SumMonoid::new());
+
+        assert_eq!(
+            ErrorKind::InvalidRange { start: 3, end: 1 },
+            tree.query(3..1).unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn query_end_out_of_bounds() {
+        let tree = SegTree::build(&[1u64, 2, 3, 4, 5], SumMonoid::new());
+
+        assert_eq!(
+            ErrorKind::InvalidRange { start: 0, end: 6 },
+            tree.query(0..6).unwrap_err().kind
+        );
+    }
+}